@@ -17,6 +17,24 @@ use std::{collections::BTreeMap, sync::LazyLock, time::Duration};
 pub struct RootClock {
     select_index: u32,
     slice_number: u64,
+    nominal: Option<Nominal>,
+    clock_root: Option<ClockRoot>,
+}
+
+/// A root clock's nominal (expected) frequency and tolerance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Nominal {
+    hz: u32,
+    tolerance_ppm: u32,
+}
+
+/// A root clock's CCM clock-root `CONTROL` register description.
+///
+/// `offset` locates the register relative to the clock-root block base
+/// (see [`Imxrt::decode_clock_root`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ClockRoot {
+    offset: u64,
 }
 
 impl RootClock {
@@ -28,9 +46,25 @@ impl RootClock {
         Self {
             select_index,
             slice_number,
+            nominal: None,
+            clock_root: None,
         }
     }
 
+    /// Attach a nominal (expected) frequency and tolerance to this root clock.
+    ///
+    /// `nominal_hz` is the frequency you expect to measure; `tolerance_ppm`
+    /// is the allowed deviation from that frequency, in parts per million.
+    /// Once set, [`Imxrt::check_tolerance`] can compare observations against
+    /// this clock.
+    pub const fn with_nominal(mut self, nominal_hz: u32, tolerance_ppm: u32) -> Self {
+        self.nominal = Some(Nominal {
+            hz: nominal_hz,
+            tolerance_ppm,
+        });
+        self
+    }
+
     /// Returns the select index for this root clock.
     pub const fn select_index(&self) -> u32 {
         self.select_index
@@ -40,6 +74,32 @@ impl RootClock {
     pub const fn slice_number(&self) -> u64 {
         self.slice_number
     }
+
+    /// Returns the nominal frequency, in Hz, if one was configured.
+    pub const fn nominal_hz(&self) -> Option<u32> {
+        match self.nominal {
+            Some(nominal) => Some(nominal.hz),
+            None => None,
+        }
+    }
+
+    /// Returns the allowed tolerance, in parts per million, if one was configured.
+    pub const fn tolerance_ppm(&self) -> Option<u32> {
+        match self.nominal {
+            Some(nominal) => Some(nominal.tolerance_ppm),
+            None => None,
+        }
+    }
+
+    /// Attach a CCM clock-root `CONTROL` register to this root clock.
+    ///
+    /// `offset` is the register's offset from the clock-root block base.
+    /// Once set, [`Imxrt::decode_clock_root`] can decode the clock's active
+    /// MUX selection and divider.
+    pub const fn with_clock_root(mut self, offset: u64) -> Self {
+        self.clock_root = Some(ClockRoot { offset });
+        self
+    }
 }
 
 /// A collection of root clocks.
@@ -80,9 +140,46 @@ pub struct Imxrt {
     /// block. For the 1180, point this at the zeroth control
     /// register (there's only one slice, apparently?).
     ccm_obs: u64,
+    /// Starting address of the CCM clock-root block.
+    ///
+    /// Root clocks' `CONTROL` registers live here, offset per
+    /// [`RootClock::with_clock_root`]. This is a distinct block from
+    /// `ccm_obs`, which only ever observes a clock's frequency; it
+    /// never explains why that clock reads what it does.
+    ///
+    /// TODO: the values used for this field (set where `Imxrt` is
+    /// constructed, below) are carried over from the CCM base address in
+    /// each MCU's memory map and have not been cross-checked register-by-
+    /// register against the reference manual. Confirm them on real
+    /// hardware (e.g. by reading a root whose source is already known,
+    /// like `OSC_24M_OUT`'s consumer) before trusting decoded output.
+    clock_root_base: u64,
     root_clocks: RootClocks,
 }
 
+/// The conservative divider used for the coarse first pass in
+/// [`Imxrt::observe_with_delay`].
+///
+/// We need to divide clocks below 400MHz. This divider is large enough to
+/// work with a 3.2GHz clock, which isn't expected to exist on i.MX RT MCUs.
+const COARSE_DIVIDER: u32 = 8;
+
+/// The largest safe input frequency (Hz) for a single divider step.
+const SAFE_INPUT_CEILING_HZ: u32 = 400_000_000;
+
+/// Pick the smallest divider that keeps `coarse_raw_current`, scaled by
+/// [`COARSE_DIVIDER`], under [`SAFE_INPUT_CEILING_HZ`], never dropping
+/// below 1. Falls back to [`COARSE_DIVIDER`] if scaling the coarse reading
+/// overflows.
+fn select_divider(coarse_raw_current: u32) -> u32 {
+    match coarse_raw_current.checked_mul(COARSE_DIVIDER) {
+        Some(estimate) => estimate
+            .div_ceil(SAFE_INPUT_CEILING_HZ)
+            .clamp(1, COARSE_DIVIDER),
+        None => COARSE_DIVIDER,
+    }
+}
+
 impl Imxrt {
     /// Returns the collection of all clock names.
     pub fn all_root_clock_names(&self) -> impl Iterator<Item = RootClockName<'_>> {
@@ -155,97 +252,398 @@ impl Imxrt {
                 let root_clock = self.get(*root_clock);
                 let slice = CcmObsSlice::for_root_clock(self.ccm_obs, root_clock);
 
-                // Direct write to control register zeros all other bits.
-                const OFF: u32 = 1 << 24;
-                mem.write_word_32(slice.control(), OFF)
-                    .map_err(context("turning off the slice"))?;
-
-                // Include the RESET bit, maintain OFF bit.
-                const RESET: u32 = 1 << 15;
-                mem.write_word_32(slice.control_set(), RESET)
-                    .map_err(context("resetting the slice"))?;
-
-                /// The divider used before clock sampling.
-                ///
-                /// We need to divide clocks below 400MHz. This divider is large
-                /// enough to work with a 3.2GHz clock, which isn't expected to
-                /// exist on i.MX RT MCUs.
-                const DIVIDER: u32 = 8;
-                const fn divider_field() -> u32 {
-                    (DIVIDER - 1) << 16
-                }
-
-                // Update the root select and the divider while keeping
-                // RESET and OFF.
-                mem.write_word_32(
-                    slice.control(),
-                    OFF | RESET | divider_field() | root_clock.select_index,
-                )
-                .map_err(context("setting the divider and root select"))?;
-
-                // Clear the OFF and RESET to begin sampling.
-                mem.write_word_32(slice.control_clr(), OFF | RESET)
-                    .map_err(context("starting to sample"))?;
-
-                // Force the probe to dispatch writes to the MCU.
-                mem.flush()
-                    .map_err(context("flushing commands to the MCU"))?;
-
-                // Wait for completion.
-                std::thread::sleep(delay);
-
-                let mut freqs = [0u32; 3];
-                mem.read_32(slice.frequency_current(), &mut freqs)
-                    .map_err(context("sampling frequencies"))?;
-
-                // We're done; turn off the slice.
-                mem.write_word_32(slice.control(), OFF)
-                    .map_err(context("turning off the slice"))?;
-
-                mem.flush()
-                    .map_err(context("flushing cleanup to the MCU"))?;
+                let coarse =
+                    Self::sample_slice(mem, slice, root_clock.select_index, COARSE_DIVIDER, delay)?;
+
+                let divider = select_divider(coarse[0]);
+
+                let freqs = if divider == COARSE_DIVIDER {
+                    coarse
+                } else {
+                    Self::sample_slice(mem, slice, root_clock.select_index, divider, delay)?
+                };
 
                 Ok(Frequencies {
                     raw_current: freqs[0],
                     raw_min: freqs[1],
                     raw_max: freqs[2],
-                    divider: DIVIDER,
+                    divider,
                 })
             })
             .collect()
     }
+
+    /// Program a slice's divider and root select, sample, then turn it off.
+    ///
+    /// Returns the raw `[current, min, max]` readings.
+    fn sample_slice(
+        mem: &mut dyn MemoryInterface,
+        slice: CcmObsSlice,
+        select_index: u32,
+        divider: u32,
+        delay: Duration,
+    ) -> Result<[u32; 3], Error> {
+        // Direct write to control register zeros all other bits.
+        const OFF: u32 = 1 << 24;
+        mem.write_word_32(slice.control(), OFF)
+            .map_err(context("turning off the slice"))?;
+
+        // Include the RESET bit, maintain OFF bit.
+        const RESET: u32 = 1 << 15;
+        mem.write_word_32(slice.control_set(), RESET)
+            .map_err(context("resetting the slice"))?;
+
+        let divider_field = (divider - 1) << 16;
+
+        // Update the root select and the divider while keeping
+        // RESET and OFF.
+        mem.write_word_32(slice.control(), OFF | RESET | divider_field | select_index)
+            .map_err(context("setting the divider and root select"))?;
+
+        // Clear the OFF and RESET to begin sampling.
+        mem.write_word_32(slice.control_clr(), OFF | RESET)
+            .map_err(context("starting to sample"))?;
+
+        // Force the probe to dispatch writes to the MCU.
+        mem.flush()
+            .map_err(context("flushing commands to the MCU"))?;
+
+        // Wait for completion.
+        std::thread::sleep(delay);
+
+        let mut freqs = [0u32; 3];
+        mem.read_32(slice.frequency_current(), &mut freqs)
+            .map_err(context("sampling frequencies"))?;
+
+        // We're done; turn off the slice.
+        mem.write_word_32(slice.control(), OFF)
+            .map_err(context("turning off the slice"))?;
+
+        mem.flush()
+            .map_err(context("flushing cleanup to the MCU"))?;
+
+        Ok(freqs)
+    }
+
+    /// Compare an observation against a root clock's nominal frequency.
+    ///
+    /// Returns `None` if `root_clock` has no nominal frequency configured
+    /// (see [`RootClock::with_nominal`]), or if `frequencies` didn't yield
+    /// a current measurement.
+    pub fn check_tolerance(
+        &self,
+        root_clock: RootClockName,
+        frequencies: &Frequencies,
+    ) -> Option<ToleranceCheck> {
+        let root_clock = self.get(root_clock);
+        let nominal = root_clock.nominal?;
+        let current = frequencies.current()?.as_hz();
+
+        Some(tolerance_check(nominal.hz, nominal.tolerance_ppm, current))
+    }
+
+    /// Repeatedly observe root clocks, accumulating jitter statistics.
+    ///
+    /// Calls [`observe_with_delay`](Self::observe_with_delay) `samples` times.
+    /// After each round, `on_sample` is invoked with the sample index, that
+    /// round's [`Frequencies`], and the running [`JitterStats`] accumulated
+    /// so far, letting a caller redraw a live view. Returns the final
+    /// [`JitterStats`], one per `root_clock`.
+    pub fn observe_stream(
+        &self,
+        root_clocks: &[RootClockName],
+        mem: &mut dyn MemoryInterface,
+        delay: Duration,
+        samples: usize,
+        mut on_sample: impl FnMut(usize, &[Frequencies], &[JitterStats]),
+    ) -> Result<Vec<JitterStats>, Error> {
+        let mut stats = vec![JitterStats::default(); root_clocks.len()];
+
+        for sample in 0..samples {
+            let frequencies = self.observe_with_delay(root_clocks, mem, delay)?;
+            for (stat, freq) in stats.iter_mut().zip(&frequencies) {
+                stat.update(freq.current());
+            }
+            on_sample(sample, &frequencies, &stats);
+        }
+
+        Ok(stats)
+    }
+
+    /// Decode a root clock's active MUX selection and divider.
+    ///
+    /// Reads the root clock's CCM clock-root `CONTROL` register and
+    /// splits it into its `MUX` and `DIV` fields. Returns `None` if
+    /// `root_clock` has no clock-root register configured; this is the
+    /// case for raw oscillator and PLL outputs, which aren't themselves
+    /// divided clock roots.
+    ///
+    /// This reports the raw `MUX` selection index rather than an upstream
+    /// source name: per-root MUX option lists differ across clock roots,
+    /// and this crate doesn't yet carry a reference-manual-verified table
+    /// mapping each root's index to a source name. Treat the index as a
+    /// hint to cross-check against the reference manual, not a resolved
+    /// source.
+    pub fn decode_clock_root(
+        &self,
+        root_clock: RootClockName,
+        mem: &mut dyn MemoryInterface,
+    ) -> Result<Option<ClockRootState>, Error> {
+        let root_clock = self.get(root_clock);
+        let Some(clock_root) = root_clock.clock_root else {
+            return Ok(None);
+        };
+
+        let control = mem
+            .read_word_32(self.clock_root_base + clock_root.offset)
+            .map_err(context("reading the clock-root CONTROL register"))?;
+
+        // MUX occupies bits [10:8]; DIV occupies bits [7:0] and is
+        // zero-based, so the programmed divider is DIV + 1.
+        let mux_index = (control >> 8) & 0x7;
+        let divider = (control & 0xFF) + 1;
+
+        Ok(Some(ClockRootState { mux_index, divider }))
+    }
+}
+
+/// The decoded state of a root clock's CCM clock-root `CONTROL` register.
+///
+/// Obtained from [`Imxrt::decode_clock_root`]. Reports the selected
+/// `MUX` index and the integer divider applied to it, so you can
+/// cross-check the derived frequency against a measured one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClockRootState {
+    mux_index: u32,
+    divider: u32,
+}
+
+impl ClockRootState {
+    /// Returns the raw `MUX` field selecting the upstream clock source.
+    ///
+    /// This crate doesn't carry a verified name table for this index; see
+    /// [`Imxrt::decode_clock_root`].
+    pub const fn mux_index(&self) -> u32 {
+        self.mux_index
+    }
+
+    /// Returns the integer divider applied to the selected source.
+    pub const fn divider(&self) -> u32 {
+        self.divider
+    }
+}
+
+/// The result of [`Imxrt::check_tolerance`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ToleranceCheck {
+    deviation_percent: f64,
+    in_spec: bool,
+}
+
+impl ToleranceCheck {
+    /// Returns how far the observed frequency deviated from nominal, in percent.
+    ///
+    /// A positive value means the observation ran fast; negative means slow.
+    pub const fn deviation_percent(&self) -> f64 {
+        self.deviation_percent
+    }
+
+    /// Returns `true` if the deviation was within the configured tolerance.
+    pub const fn in_spec(&self) -> bool {
+        self.in_spec
+    }
+}
+
+/// Compare `current_hz` against `nominal_hz`, allowed to deviate by
+/// `tolerance_ppm` parts per million.
+fn tolerance_check(nominal_hz: u32, tolerance_ppm: u32, current_hz: u32) -> ToleranceCheck {
+    let deviation_percent =
+        (f64::from(current_hz) - f64::from(nominal_hz)) / f64::from(nominal_hz) * 100.0;
+    let tolerance_percent = f64::from(tolerance_ppm) / 10_000.0;
+
+    ToleranceCheck {
+        deviation_percent,
+        in_spec: deviation_percent.abs() <= tolerance_percent,
+    }
+}
+
+/// Jitter statistics accumulated over a sequence of samples.
+///
+/// Built up across repeated calls to [`Imxrt::observe_stream`]. Tracks the
+/// global minimum and maximum current frequency observed, and the delta
+/// between the two most recent samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct JitterStats {
+    min: Option<Frequency>,
+    max: Option<Frequency>,
+    last_current: Option<Frequency>,
+    last_delta_hz: Option<u32>,
+}
+
+impl JitterStats {
+    /// Fold in a new current-frequency sample, or do nothing if the sample
+    /// didn't yield one.
+    fn update(&mut self, current: Option<Frequency>) {
+        let Some(current) = current else {
+            return;
+        };
+
+        self.min = Some(self.min.map_or(current, |min| min.min(current)));
+        self.max = Some(self.max.map_or(current, |max| max.max(current)));
+        self.last_delta_hz = self
+            .last_current
+            .map(|previous| current.as_hz().abs_diff(previous.as_hz()));
+        self.last_current = Some(current);
+    }
+
+    /// Returns the lowest current frequency observed so far.
+    pub const fn min(&self) -> Option<Frequency> {
+        self.min
+    }
+
+    /// Returns the highest current frequency observed so far.
+    pub const fn max(&self) -> Option<Frequency> {
+        self.max
+    }
+
+    /// Returns the peak-to-peak spread (max minus min) observed so far.
+    pub fn peak_to_peak(&self) -> Option<Frequency> {
+        let max = self.max?.as_hz();
+        let min = self.min?.as_hz();
+        Some(Frequency::from_hz(max.saturating_sub(min)))
+    }
+
+    /// Returns the absolute delta between the two most recent samples.
+    ///
+    /// Returns `None` until at least two samples have been observed.
+    pub const fn last_delta(&self) -> Option<Frequency> {
+        match self.last_delta_hz {
+            Some(hz) => Some(Frequency::from_hz(hz)),
+            None => None,
+        }
+    }
 }
 
 fn root_clock(name: &'static str, select_index: u32, slice_number: u64) -> (String, RootClock) {
     (name.into(), RootClock::new(select_index, slice_number))
 }
 
+/// Same as [`root_clock`], but also attaches a nominal frequency and
+/// tolerance (in parts per million) via [`RootClock::with_nominal`].
+fn root_clock_with_nominal(
+    name: &'static str,
+    select_index: u32,
+    slice_number: u64,
+    nominal_hz: u32,
+    tolerance_ppm: u32,
+) -> (String, RootClock) {
+    let (name, root_clock) = root_clock(name, select_index, slice_number);
+    (name, root_clock.with_nominal(nominal_hz, tolerance_ppm))
+}
+
+/// Same as [`root_clock`], but also attaches a CCM clock-root `CONTROL`
+/// register via [`RootClock::with_clock_root`].
+///
+/// The CCM_OBS `select_index` for a clock root is `clock_root_select_base
+/// + root_index`, and a root's `CONTROL` register sits `root_index * 0x80`
+/// past the clock-root block base. So rather than take an offset directly
+/// (which earlier tied it to a root's position in the enumerating `vec![]`
+/// instead of its real clock-root index), derive it from `select_index`
+/// and the MCU's `clock_root_select_base` — the `select_index` of root 0.
+fn root_clock_with_clock_root(
+    name: &'static str,
+    select_index: u32,
+    slice_number: u64,
+    clock_root_select_base: u32,
+) -> (String, RootClock) {
+    let clock_root_offset = u64::from(select_index - clock_root_select_base) * 0x80;
+    let (name, root_clock) = root_clock(name, select_index, slice_number);
+    (name, root_clock.with_clock_root(clock_root_offset))
+}
+
+/// The CCM_OBS `select_index` of the 1170's clock-root index 0 (`M7_CLK_ROOT`).
+///
+/// Clock roots are enumerated contiguously from here, so
+/// `select_index - IMXRT1170_CLOCK_ROOT_SELECT_BASE` gives a root's index
+/// and, scaled by `0x80`, its `CONTROL` register offset.
+const IMXRT1170_CLOCK_ROOT_SELECT_BASE: u32 = 128;
+
 /// Provides access to the CCM_OBS on 1170 MCUs.
 ///
 /// See [`Imxrt`] for more information.
 pub static IMXRT1170: LazyLock<Imxrt> = LazyLock::new(|| {
     let root_clocks = vec![
-        root_clock("M7_CLK_ROOT", 128, 4),
-        root_clock("M4_CLK_ROOT", 129, 0),
-        root_clock("BUS_CLK_ROOT", 130, 2),
-        root_clock("BUS_CLK_LPSR_CLK_ROOT", 131, 0),
-        root_clock("M4_SYSTICK_CLK_ROOT", 135, 0),
-        root_clock("M7_SYSTICK_CLK_ROOT", 136, 2),
-        root_clock("ENET1_CLK_ROOT", 179, 2),
-        root_clock("ENET2_CLK_ROOT", 180, 2),
-        root_clock("ENET_QOS_CLK_ROOT", 181, 2),
-        root_clock("ENET_25M_CLK_ROOT", 182, 2),
-        root_clock("ENET_TIMER1_CLK_ROOT", 183, 2),
-        root_clock("ENET_TIMER2_CLK_ROOT", 184, 2),
-        root_clock("ENET_TIMER3_CLK_ROOT", 185, 2),
-        root_clock("OSC_RC_400M", 227, 0),
-        root_clock("OSC_24M_OUT", 229, 0),
+        root_clock_with_clock_root("M7_CLK_ROOT", 128, 4, IMXRT1170_CLOCK_ROOT_SELECT_BASE),
+        root_clock_with_clock_root("M4_CLK_ROOT", 129, 0, IMXRT1170_CLOCK_ROOT_SELECT_BASE),
+        root_clock_with_clock_root("BUS_CLK_ROOT", 130, 2, IMXRT1170_CLOCK_ROOT_SELECT_BASE),
+        root_clock_with_clock_root(
+            "BUS_CLK_LPSR_CLK_ROOT",
+            131,
+            0,
+            IMXRT1170_CLOCK_ROOT_SELECT_BASE,
+        ),
+        root_clock_with_clock_root(
+            "M4_SYSTICK_CLK_ROOT",
+            135,
+            0,
+            IMXRT1170_CLOCK_ROOT_SELECT_BASE,
+        ),
+        root_clock_with_clock_root(
+            "M7_SYSTICK_CLK_ROOT",
+            136,
+            2,
+            IMXRT1170_CLOCK_ROOT_SELECT_BASE,
+        ),
+        root_clock_with_clock_root("ENET1_CLK_ROOT", 179, 2, IMXRT1170_CLOCK_ROOT_SELECT_BASE),
+        root_clock_with_clock_root("ENET2_CLK_ROOT", 180, 2, IMXRT1170_CLOCK_ROOT_SELECT_BASE),
+        root_clock_with_clock_root(
+            "ENET_QOS_CLK_ROOT",
+            181,
+            2,
+            IMXRT1170_CLOCK_ROOT_SELECT_BASE,
+        ),
+        root_clock_with_clock_root(
+            "ENET_25M_CLK_ROOT",
+            182,
+            2,
+            IMXRT1170_CLOCK_ROOT_SELECT_BASE,
+        ),
+        root_clock_with_clock_root(
+            "ENET_TIMER1_CLK_ROOT",
+            183,
+            2,
+            IMXRT1170_CLOCK_ROOT_SELECT_BASE,
+        ),
+        root_clock_with_clock_root(
+            "ENET_TIMER2_CLK_ROOT",
+            184,
+            2,
+            IMXRT1170_CLOCK_ROOT_SELECT_BASE,
+        ),
+        root_clock_with_clock_root(
+            "ENET_TIMER3_CLK_ROOT",
+            185,
+            2,
+            IMXRT1170_CLOCK_ROOT_SELECT_BASE,
+        ),
+        // 400MHz RC oscillator; datasheet gives it a loose +/-15% accuracy.
+        // This is a raw source, not a divided clock root, so it has no
+        // CCM clock-root CONTROL register of its own.
+        root_clock_with_nominal("OSC_RC_400M", 227, 0, 400_000_000, 150_000),
+        // 24MHz crystal oscillator; assume a typical +/-50ppm crystal.
+        root_clock_with_nominal("OSC_24M_OUT", 229, 0, 24_000_000, 50),
     ]
     .into_iter()
     .collect();
 
     Imxrt {
         ccm_obs: 0x4015_0000,
+        // The 1170's CCM (non-OBS) peripheral, where clock-root CONTROL
+        // registers live, is documented at 0x4012_0000 in the i.MX RT1170
+        // memory map — a separate, lower block than `ccm_obs` above.
+        // Unverified against a physical part; see the TODO on
+        // `Imxrt::clock_root_base`.
+        clock_root_base: 0x4012_0000,
         root_clocks,
     }
 });
@@ -256,14 +654,21 @@ pub static IMXRT1170: LazyLock<Imxrt> = LazyLock::new(|| {
 pub static IMXRT1180: LazyLock<Imxrt> = LazyLock::new(|| {
     let root_clocks = vec![
         root_clock("OSC_RC_24M", 2, 0),
-        root_clock("OSC_RC_400M", 3, 0),
-        root_clock("OSC_24M_OUT", 5, 0),
+        // 400MHz RC oscillator; datasheet gives it a loose +/-15% accuracy.
+        root_clock_with_nominal("OSC_RC_400M", 3, 0, 400_000_000, 150_000),
+        // 24MHz crystal oscillator; assume a typical +/-50ppm crystal.
+        root_clock_with_nominal("OSC_24M_OUT", 5, 0, 24_000_000, 50),
         root_clock("PLL_480_OUT", 15, 0),
         root_clock("PLL_480_DIV2", 16, 0),
         root_clock("PLL_480_PFD0", 17, 0),
         root_clock("PLL_480_PFD1", 18, 0),
         root_clock("PLL_480_PFD2", 19, 0),
         root_clock("PLL_480_PFD3", 20, 0),
+        // `M33_CLK_ROOT` and `FLEXSPI1_CLK_ROOT` deliberately don't carry a
+        // `with_clock_root` registration yet: unlike the 1170, the 1180's
+        // mapping from CCM_OBS `select_index` to clock-root `CONTROL`
+        // register offset hasn't been confirmed against the reference
+        // manual, so there's nothing trustworthy to decode from yet.
         root_clock("M33_CLK_ROOT", 129, 0),
         root_clock("FLEXSPI1_CLK_ROOT", 149, 0),
     ]
@@ -272,13 +677,64 @@ pub static IMXRT1180: LazyLock<Imxrt> = LazyLock::new(|| {
 
     Imxrt {
         ccm_obs: 0x4445_0000 + 0x4400,
+        // The 1180's CCM block is documented at 0x4445_0000; its
+        // clock-root CONTROL registers start at the block base, with
+        // CCM_OBS's own registers at the +0x4400 offset used above. Not
+        // currently read by any 1180 root clock (none carry a clock-root
+        // registration yet); see the TODO on `Imxrt::clock_root_base`.
+        clock_root_base: 0x4445_0000,
         root_clocks,
     }
 });
 
+/// A measured frequency, in Hz.
+///
+/// Construct one with [`Frequency::from_hz`], then read it back with
+/// whichever scale reads best: [`as_hz`](Self::as_hz), [`as_khz`](Self::as_khz),
+/// or [`as_mhz`](Self::as_mhz). The [`Display`](std::fmt::Display) impl picks
+/// a human-readable scale automatically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Frequency(u32);
+
+impl Frequency {
+    /// Wrap a raw Hz measurement.
+    pub const fn from_hz(hz: u32) -> Self {
+        Self(hz)
+    }
+
+    /// Return the frequency in Hz.
+    pub const fn as_hz(&self) -> u32 {
+        self.0
+    }
+
+    /// Return the frequency in kHz.
+    pub const fn as_khz(&self) -> f64 {
+        self.0 as f64 / 1_000.0
+    }
+
+    /// Return the frequency in MHz.
+    pub const fn as_mhz(&self) -> f64 {
+        self.0 as f64 / 1_000_000.0
+    }
+}
+
+impl std::fmt::Display for Frequency {
+    /// Auto-selects Hz, kHz, or MHz, whichever reads best, with two
+    /// decimal places for the scaled units.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0 >= 1_000_000 {
+            write!(f, "{:.2} MHz", self.as_mhz())
+        } else if self.0 >= 1_000 {
+            write!(f, "{:.2} kHz", self.as_khz())
+        } else {
+            write!(f, "{} Hz", self.0)
+        }
+    }
+}
+
 /// Frequency measurements provided by the CCM_OBS peripheral block.
 ///
-/// You may access the current, minimum, and maximum frequencies (Hz)
+/// You may access the current, minimum, and maximum frequencies
 /// using [`current`](Self::current), [`min`](Self::min), and [`max`](Self::max)
 /// respectively. These values are scaled by a divider, set as an implementation
 /// detail. If the frequency overflows, the return is `None`.
@@ -293,33 +749,42 @@ pub struct Frequencies {
 }
 
 impl Frequencies {
-    /// Return the current frequency measurement, in Hz.
+    /// Return the current frequency measurement.
     ///
     /// Returns `None` if multiplication with the divider
     /// occurred.
-    pub const fn current(&self) -> Option<u32> {
-        self.raw_current.checked_mul(self.divider)
+    pub const fn current(&self) -> Option<Frequency> {
+        match self.raw_current.checked_mul(self.divider) {
+            Some(hz) => Some(Frequency::from_hz(hz)),
+            None => None,
+        }
     }
-    /// Return the minimum frequency observed, in Hz.
+    /// Return the minimum frequency observed.
     ///
     /// Returns `None` if multiplication with the divider
     /// occurred.
-    pub const fn min(&self) -> Option<u32> {
-        self.raw_min.checked_mul(self.divider)
+    pub const fn min(&self) -> Option<Frequency> {
+        match self.raw_min.checked_mul(self.divider) {
+            Some(hz) => Some(Frequency::from_hz(hz)),
+            None => None,
+        }
     }
-    /// Return the maximum frequency observed, in Hz.
+    /// Return the maximum frequency observed.
     ///
     /// Returns `None` if multiplication with the divider
     /// occurred.
-    pub const fn max(&self) -> Option<u32> {
-        self.raw_max.checked_mul(self.divider)
+    pub const fn max(&self) -> Option<Frequency> {
+        match self.raw_max.checked_mul(self.divider) {
+            Some(hz) => Some(Frequency::from_hz(hz)),
+            None => None,
+        }
     }
 
     /// Compute the difference in max and min.
-    pub fn diff(&self) -> Option<u32> {
-        let max = self.max()?;
-        let min = self.min()?;
-        Some(max.saturating_sub(min))
+    pub fn diff(&self) -> Option<Frequency> {
+        let max = self.max()?.as_hz();
+        let min = self.min()?.as_hz();
+        Some(Frequency::from_hz(max.saturating_sub(min)))
     }
 
     /// Return the raw measurement observed by the peripheral.
@@ -389,3 +854,68 @@ impl CcmObsSlice {
         self.0 + 0x40
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn select_divider_picks_one_when_headroom_is_ample() {
+        assert_eq!(select_divider(0), 1);
+        assert_eq!(select_divider(50_000_000), 1);
+    }
+
+    #[test]
+    fn select_divider_scales_up_to_stay_under_the_ceiling() {
+        assert_eq!(select_divider(100_000_000), 2);
+    }
+
+    #[test]
+    fn select_divider_falls_back_to_coarse_on_overflow() {
+        assert_eq!(select_divider(u32::MAX), COARSE_DIVIDER);
+    }
+
+    #[test]
+    fn display_switches_scale_at_unit_boundaries() {
+        assert_eq!(Frequency::from_hz(999).to_string(), "999 Hz");
+        assert_eq!(Frequency::from_hz(1_000).to_string(), "1.00 kHz");
+        assert_eq!(Frequency::from_hz(999_999).to_string(), "1000.00 kHz");
+        assert_eq!(Frequency::from_hz(1_000_000).to_string(), "1.00 MHz");
+    }
+
+    #[test]
+    fn tolerance_check_in_spec_at_the_edge() {
+        // 24MHz nominal, 50ppm tolerance allows +/-1200Hz (0.005%).
+        assert!(tolerance_check(24_000_000, 50, 24_001_200).in_spec());
+        assert!(tolerance_check(24_000_000, 50, 23_998_800).in_spec());
+    }
+
+    #[test]
+    fn tolerance_check_out_of_spec_past_the_edge() {
+        assert!(!tolerance_check(24_000_000, 50, 24_001_201).in_spec());
+        assert!(!tolerance_check(24_000_000, 50, 23_998_799).in_spec());
+    }
+
+    #[test]
+    fn jitter_stats_tracks_min_max_and_last_delta() {
+        let mut stats = JitterStats::default();
+        stats.update(Some(Frequency::from_hz(100)));
+        stats.update(Some(Frequency::from_hz(150)));
+        stats.update(Some(Frequency::from_hz(90)));
+
+        assert_eq!(stats.min(), Some(Frequency::from_hz(90)));
+        assert_eq!(stats.max(), Some(Frequency::from_hz(150)));
+        assert_eq!(stats.last_delta(), Some(Frequency::from_hz(60)));
+        assert_eq!(stats.peak_to_peak(), Some(Frequency::from_hz(60)));
+    }
+
+    #[test]
+    fn jitter_stats_ignores_missing_samples() {
+        let mut stats = JitterStats::default();
+        stats.update(Some(Frequency::from_hz(100)));
+        stats.update(None);
+
+        assert_eq!(stats.max(), Some(Frequency::from_hz(100)));
+        assert_eq!(stats.last_delta(), None);
+    }
+}
@@ -34,6 +34,26 @@ struct Cli {
     /// on its execution path.
     #[arg(long)]
     after_halted_reset: bool,
+
+    /// Continuously re-sample and redraw the table in place.
+    ///
+    /// Accumulates min, max, and peak-to-peak jitter across all samples
+    /// taken, in addition to the current reading.
+    #[arg(long)]
+    watch: bool,
+
+    /// Number of samples to take in `--watch` mode. Ignored otherwise.
+    #[arg(long, default_value = "100")]
+    samples: usize,
+
+    /// Also decode and print each root clock's MUX selection and divider.
+    ///
+    /// This reads the CCM clock-root `CONTROL` register directly, which
+    /// relies on clock-root base addresses and offsets that haven't been
+    /// verified against real hardware. Off by default so the tool doesn't
+    /// present unverified decode output next to a real measurement.
+    #[arg(long)]
+    decode: bool,
 }
 
 #[derive(Clone, ValueEnum)]
@@ -58,7 +78,7 @@ impl Mcu {
     }
 }
 
-fn freq_to_str(freq: Option<u32>) -> String {
+fn freq_to_str(freq: Option<ccm_obs::Frequency>) -> String {
     freq.map(|f| f.to_string())
         .unwrap_or_else(|| String::from("???"))
 }
@@ -112,23 +132,103 @@ fn main() {
 
     let delay = cli.delay_ms.map(Duration::from_millis).unwrap();
 
+    if cli.watch {
+        mcu.observe_stream(
+            &names,
+            &mut core,
+            delay,
+            cli.samples,
+            |sample, frequencies, stats| {
+                // Clear the screen and move the cursor home to redraw in place.
+                print!("\x1B[2J\x1B[H");
+                println!("Sample {}/{}", sample + 1, cli.samples);
+                println!(
+                    "{:>30} | {:>12} | {:>12} | {:>12} | {:>12} | {:>12}",
+                    "Name", "Current", "Min", "Max", "Peak-Peak", "Last Delta"
+                );
+                let line: String = "-".repeat(30 + (5 * 3) + (12 * 5));
+                println!("{line}");
+
+                for ((name, freq), stats) in names.iter().zip(frequencies).zip(stats) {
+                    println!(
+                        "{:>30} | {:>12} | {:>12} | {:>12} | {:>12} | {:>12}",
+                        name,
+                        freq_to_str(freq.current()),
+                        freq_to_str(stats.min()),
+                        freq_to_str(stats.max()),
+                        freq_to_str(stats.peak_to_peak()),
+                        freq_to_str(stats.last_delta()),
+                    );
+                }
+            },
+        )
+        .unwrap();
+        return;
+    }
+
     let frequencies = mcu.observe_with_delay(&names, &mut core, delay).unwrap();
 
+    if cli.decode {
+        println!(
+            "Note: the Decode column reads unverified clock-root base addresses; \
+             treat it as a hint, not ground truth."
+        );
+    }
+
     println!(
-        "{:>30} | {:>12} | {:>12} | {:>12} | {:>12}",
-        "Name", "Current (Hz)", "Min (Hz)", "Max (Hz)", "Max-Min (Hz)"
+        "{:>30} | {:>12} | {:>12} | {:>12} | {:>12} | {:>10}{}",
+        "Name",
+        "Current",
+        "Min",
+        "Max",
+        "Max-Min",
+        "Deviation",
+        cli.decode
+            .then(|| format!(" | {:>20}", "Decode"))
+            .unwrap_or_default(),
     );
-    let line: String = "-".repeat(30 + (4 * 3) + (12 * 4));
+    let line: String =
+        "-".repeat(30 + (5 * 3) + (12 * 4) + 10 + if cli.decode { 3 + 20 } else { 0 });
     println!("{line}");
 
     for (name, frequencies) in names.iter().zip(frequencies) {
+        let decode = cli
+            .decode
+            .then(|| {
+                format!(
+                    " | {:>20}",
+                    clock_root_to_str(mcu.decode_clock_root(*name, &mut core).unwrap())
+                )
+            })
+            .unwrap_or_default();
         println!(
-            "{:>30} | {:>12} | {:>12} | {:>12} | {:>12}",
+            "{:>30} | {:>12} | {:>12} | {:>12} | {:>12} | {:>10}{}",
             name,
             freq_to_str(frequencies.current()),
             freq_to_str(frequencies.min()),
             freq_to_str(frequencies.max()),
-            freq_to_str(frequencies.diff())
+            freq_to_str(frequencies.diff()),
+            deviation_to_str(mcu.check_tolerance(*name, &frequencies)),
+            decode,
         );
     }
 }
+
+fn deviation_to_str(check: Option<ccm_obs::ToleranceCheck>) -> String {
+    match check {
+        Some(check) if check.in_spec() => format!("{:+.3}%", check.deviation_percent()),
+        Some(check) => format!("{:+.3}% !", check.deviation_percent()),
+        None => String::from("-"),
+    }
+}
+
+fn clock_root_to_str(clock_root: Option<ccm_obs::ClockRootState>) -> String {
+    match clock_root {
+        Some(clock_root) => format!(
+            "mux={}/div={}",
+            clock_root.mux_index(),
+            clock_root.divider()
+        ),
+        None => String::from("-"),
+    }
+}